@@ -10,8 +10,8 @@ impl snx::App for App {
             .add_routes(&[
                 Route::get("/"),
                 Route::get("/posts"),
-                Route::get("/posts/:id"),
-                Route::get("/posts/:id/comments"),
+                Route::get("/posts/{id}"),
+                Route::get("/posts/{id}/comments"),
             ])
             .build()
     }
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 
+use super::method::Method;
+
 pub struct Request {
+    pub method: Method,
     pub path: String,
     pub headers: HashMap<String, String>,
 }
@@ -0,0 +1,10 @@
+/// A fallback handler for unmatched paths and error statuses, scoped to a
+/// base path.
+///
+/// A `status` of `None` means the catcher matches any status, as registered
+/// by [`RouterBuilder::add_catcher_any`](super::router::RouterBuilder::add_catcher_any).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Catcher {
+    pub status: Option<u16>,
+    pub base: &'static str,
+}
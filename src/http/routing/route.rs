@@ -1,9 +1,12 @@
 use crate::http::method::Method;
 
 /// Represents a route for the application.
+///
+/// `method` is `None` for a route added with [`Route::any`], meaning it
+/// matches a request regardless of its [Method].
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Route {
-    pub method: Method,
+    pub method: Option<Method>,
     pub path: &'static str,
 }
 
@@ -11,7 +14,7 @@ impl Route {
     /// Creates a new `GET` [Route].
     pub fn get(path: &'static str) -> Self {
         Self {
-            method: Method::GET,
+            method: Some(Method::GET),
             path,
         }
     }
@@ -19,8 +22,53 @@ impl Route {
     /// Creates a new `POST` [Route].
     pub fn post(path: &'static str) -> Self {
         Self {
-            method: Method::POST,
+            method: Some(Method::POST),
             path,
         }
     }
+
+    /// Creates a new `PUT` [Route].
+    pub fn put(path: &'static str) -> Self {
+        Self {
+            method: Some(Method::PUT),
+            path,
+        }
+    }
+
+    /// Creates a new `DELETE` [Route].
+    pub fn delete(path: &'static str) -> Self {
+        Self {
+            method: Some(Method::DELETE),
+            path,
+        }
+    }
+
+    /// Creates a new `PATCH` [Route].
+    pub fn patch(path: &'static str) -> Self {
+        Self {
+            method: Some(Method::PATCH),
+            path,
+        }
+    }
+
+    /// Creates a new `HEAD` [Route].
+    pub fn head(path: &'static str) -> Self {
+        Self {
+            method: Some(Method::HEAD),
+            path,
+        }
+    }
+
+    /// Creates a new `OPTIONS` [Route].
+    pub fn options(path: &'static str) -> Self {
+        Self {
+            method: Some(Method::OPTIONS),
+            path,
+        }
+    }
+
+    /// Creates a new [Route] that matches a request regardless of its [Method].
+    pub fn any(path: &'static str) -> Self {
+        Self { method: None, path }
+    }
 }
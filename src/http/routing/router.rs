@@ -1,10 +1,310 @@
 use std::collections::HashMap;
+use std::fmt;
 
-use crate::http::request::Request;
+use crate::http::{method::Method, request::Request};
 
+use super::catcher::Catcher;
 use super::route::Route;
 
-const DYNAMIC_CHARS: [char; 2] = [':', '*'];
+/// Splits a catcher's base path into segments, trimming leading/trailing
+/// slashes.
+fn base_segments(base: &str) -> Vec<&str> {
+    base.split('/').filter(|s| !s.is_empty()).collect()
+}
+
+/// Whether `base_segments` is a prefix of `path_segments`.
+fn base_matches(path_segments: &[&str], base_segments: &[&str]) -> bool {
+    base_segments.len() <= path_segments.len()
+        && base_segments
+            .iter()
+            .zip(path_segments.iter())
+            .all(|(base, path)| base == path)
+}
+
+/// A type constraint on a `{name:kind}` param segment, checked against the
+/// corresponding request segment during matching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParamKind {
+    Str,
+    Int,
+    Uuid,
+}
+
+impl ParamKind {
+    /// Parses a `:kind` suffix, panicking on an unrecognized constraint so
+    /// that a typo surfaces at build time rather than silently matching
+    /// nothing.
+    fn parse(raw: &str) -> Self {
+        match raw {
+            "str" => Self::Str,
+            "int" => Self::Int,
+            "uuid" => Self::Uuid,
+            other => panic!("unknown param type constraint `{other}`"),
+        }
+    }
+
+    /// Whether `value` satisfies this constraint.
+    fn matches(self, value: &str) -> bool {
+        match self {
+            Self::Str => true,
+            Self::Int => !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()),
+            Self::Uuid => is_uuid(value),
+        }
+    }
+}
+
+/// The order param children are tried in during matching: the more specific
+/// (and therefore more likely to reject) constraints first, falling through
+/// to the unconstrained `Str` child last.
+const PARAM_KIND_ORDER: [ParamKind; 3] = [ParamKind::Int, ParamKind::Uuid, ParamKind::Str];
+
+/// Whether `value` is a hyphenated UUID (`8-4-4-4-12` hex digit groups).
+/// Hand-rolled since the crate has no dependency on the `uuid` crate.
+fn is_uuid(value: &str) -> bool {
+    let groups: Vec<_> = value.split('-').collect();
+    let lengths = [8, 4, 4, 4, 12];
+
+    groups.len() == lengths.len()
+        && groups
+            .iter()
+            .zip(lengths)
+            .all(|(group, len)| group.len() == len && group.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// A single parsed path segment, used both to build the matcher tree and to
+/// decide whether two routes collide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Static(String),
+    Param { name: String, kind: ParamKind },
+    CatchAll { name: String },
+}
+
+/// Renders a param back into the brace-form syntax it was parsed from, for
+/// error messages.
+fn param_brace(name: &str, kind: ParamKind) -> String {
+    match kind {
+        ParamKind::Str => format!("{{{name}}}"),
+        ParamKind::Int => format!("{{{name}:int}}"),
+        ParamKind::Uuid => format!("{{{name}:uuid}}"),
+    }
+}
+
+/// Parses a raw path segment into static, brace-form param (`{name}` or
+/// `{name:kind}`), or brace-form catch-all (`{*name}`).
+fn parse_segment(segment: &str) -> Segment {
+    let Some(inner) = segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) else {
+        return Segment::Static(segment.to_string());
+    };
+
+    if let Some(name) = inner.strip_prefix('*') {
+        return Segment::CatchAll {
+            name: name.to_string(),
+        };
+    }
+
+    match inner.split_once(':') {
+        Some((name, kind)) => Segment::Param {
+            name: name.to_string(),
+            kind: ParamKind::parse(kind),
+        },
+        None => Segment::Param {
+            name: inner.to_string(),
+            kind: ParamKind::Str,
+        },
+    }
+}
+
+/// Two routes collide if they conflict in the matcher tree, as
+/// [paths_may_collide] describes; a request matching both is not by itself a
+/// collision, since precedence settles it. They only ever land in the same tree — and so can only structurally conflict — when
+/// their methods are identical or both method-less; a concrete method and a
+/// method-less route on the same path are resolved by precedence instead,
+/// per [`Router::route`].
+fn routes_collide(a: &Route, b: &Route) -> bool {
+    let same_tree = match (&a.method, &b.method) {
+        (Some(a), Some(b)) => a == b,
+        (None, None) => true,
+        _ => false,
+    };
+
+    same_tree && paths_may_collide(a.path, b.path)
+}
+
+/// Mirrors the precedence a [Node] actually gives its children: a static
+/// segment always wins over a param or catch-all one at the same position,
+/// and two params constrained to different kinds land in separate param
+/// children and so coexist without ambiguity. The only genuine conflicts are
+/// two same-kind params bound to different names (which a tree node rejects
+/// on insertion), two catch-alls, or two fully identical paths (either of
+/// which would silently overwrite the other in a single tree node).
+fn paths_may_collide(a: &str, b: &str) -> bool {
+    let a_segments: Vec<_> = a.split('/').filter(|s| !s.is_empty()).collect();
+    let b_segments: Vec<_> = b.split('/').filter(|s| !s.is_empty()).collect();
+
+    for (a_segment, b_segment) in a_segments.iter().zip(b_segments.iter()) {
+        match (parse_segment(a_segment), parse_segment(b_segment)) {
+            (Segment::Static(a), Segment::Static(b)) if a == b => {}
+            (Segment::Static(_), _) | (_, Segment::Static(_)) => return false,
+            (Segment::Param { .. }, Segment::CatchAll { .. })
+            | (Segment::CatchAll { .. }, Segment::Param { .. }) => return false,
+            (Segment::Param { kind: a, .. }, Segment::Param { kind: b, .. }) if a != b => {
+                return false;
+            }
+            (Segment::Param { name: a, .. }, Segment::Param { name: b, .. }) if a != b => {
+                return true;
+            }
+            (Segment::CatchAll { .. }, Segment::CatchAll { .. }) => return true,
+            _ => {}
+        }
+    }
+
+    a_segments.len() == b_segments.len()
+}
+
+/// Returned by [`RouterBuilder::try_build`] when two or more added routes
+/// conflict in the matcher tree, e.g. two same-kind params bound to
+/// different names at the same position, or two identical paths.
+#[derive(Debug)]
+pub struct RouterError {
+    pub collisions: Vec<(Route, Route)>,
+}
+
+impl fmt::Display for RouterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "found {} colliding route(s):", self.collisions.len())?;
+
+        for (a, b) in &self.collisions {
+            writeln!(f, "  - {a:?} collides with {b:?}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for RouterError {}
+
+/// A single node of the radix tree that backs a [Router].
+///
+/// A node owns a segment's static children by name, its param children (at
+/// most one per [ParamKind], since a `{name:int}` and a `{name}` occupy
+/// separate slots while two `{name:int}`/`{other:int}` would not), and at
+/// most one catch-all child that terminates the tree by consuming every
+/// remaining segment. A node carries a terminal [Route] only when the path
+/// leading to it is itself a complete route.
+#[derive(Debug, Default)]
+struct Node {
+    static_children: HashMap<String, Node>,
+    param_children: Vec<ParamChild>,
+    catch_all_child: Option<Box<CatchAllChild>>,
+    route: Option<Route>,
+}
+
+#[derive(Debug)]
+struct ParamChild {
+    name: String,
+    kind: ParamKind,
+    node: Node,
+}
+
+#[derive(Debug)]
+struct CatchAllChild {
+    name: String,
+    route: Route,
+}
+
+impl Node {
+    /// Inserts `route` into the tree, creating intermediate nodes as needed.
+    fn insert(&mut self, segments: &[&str], route: Route) {
+        let Some((segment, rest)) = segments.split_first() else {
+            self.route = Some(route);
+            return;
+        };
+
+        match parse_segment(segment) {
+            Segment::Static(segment) => {
+                self.static_children
+                    .entry(segment)
+                    .or_default()
+                    .insert(rest, route);
+            }
+            Segment::Param { name, kind } => {
+                let existing = self.param_children.iter().position(|c| c.kind == kind);
+
+                let index = existing.unwrap_or_else(|| {
+                    self.param_children.push(ParamChild {
+                        name: name.clone(),
+                        kind,
+                        node: Node::default(),
+                    });
+
+                    self.param_children.len() - 1
+                });
+
+                let child = &mut self.param_children[index];
+
+                if child.name != name {
+                    panic!(
+                        "param `{}` collides with `{}` already registered at this position",
+                        param_brace(&name, kind),
+                        param_brace(&child.name, kind),
+                    );
+                }
+
+                child.node.insert(rest, route);
+            }
+            Segment::CatchAll { name } => {
+                if !rest.is_empty() {
+                    panic!(
+                        "catch-all segment `{segment}` must be the last segment of a route path"
+                    );
+                }
+
+                self.catch_all_child = Some(Box::new(CatchAllChild { name, route }));
+            }
+        }
+    }
+
+    /// Walks the tree, preferring static children, then param children (in
+    /// [PARAM_KIND_ORDER], falling through on a type mismatch), then the
+    /// catch-all child, returning the matched [Route] along with any
+    /// captured params.
+    fn find(&self, segments: &[&str]) -> Option<(Route, HashMap<String, String>)> {
+        let Some((segment, rest)) = segments.split_first() else {
+            return self.route.clone().map(|route| (route, HashMap::new()));
+        };
+
+        if let Some(child) = self.static_children.get(*segment) {
+            if let Some(matched) = child.find(rest) {
+                return Some(matched);
+            }
+        }
+
+        for kind in PARAM_KIND_ORDER {
+            let Some(param) = self.param_children.iter().find(|c| c.kind == kind) else {
+                continue;
+            };
+
+            if !kind.matches(segment) {
+                continue;
+            }
+
+            if let Some((route, mut params)) = param.node.find(rest) {
+                params.insert(param.name.clone(), (*segment).to_string());
+                return Some((route, params));
+            }
+        }
+
+        if let Some(catch_all) = &self.catch_all_child {
+            let mut params = HashMap::new();
+            params.insert(catch_all.name.clone(), segments.join("/"));
+
+            return Some((catch_all.route.clone(), params));
+        }
+
+        None
+    }
+}
 
 /// Used to build a [Router].
 ///
@@ -16,12 +316,45 @@ const DYNAMIC_CHARS: [char; 2] = [':', '*'];
 #[derive(Debug)]
 pub struct RouterBuilder {
     pub routes: Vec<Route>,
+    pub catchers: Vec<Catcher>,
 }
 
 impl RouterBuilder {
     /// Constructs a new [RouterBuilder].
     pub fn new() -> Self {
-        Self { routes: vec![] }
+        Self {
+            routes: vec![],
+            catchers: vec![],
+        }
+    }
+
+    /// Registers a catcher for `status`, scoped to `base`.
+    ///
+    /// ```
+    /// use snx::http::routing::router::Router;
+    ///
+    /// let builder = Router::builder().add_catcher(404, "/api");
+    /// ```
+    pub fn add_catcher(mut self, status: u16, base: &'static str) -> Self {
+        self.catchers.push(Catcher {
+            status: Some(status),
+            base,
+        });
+
+        self
+    }
+
+    /// Registers a catcher that matches any status, scoped to `base`.
+    ///
+    /// ```
+    /// use snx::http::routing::router::Router;
+    ///
+    /// let builder = Router::builder().add_catcher_any("/");
+    /// ```
+    pub fn add_catcher_any(mut self, base: &'static str) -> Self {
+        self.catchers.push(Catcher { status: None, base });
+
+        self
     }
 
     /// Adds a route.
@@ -56,26 +389,128 @@ impl RouterBuilder {
         self
     }
 
-    /// Builds the [Router].
+    /// Builds the [Router], folding every added route into a radix tree per
+    /// [Method], plus a separate tree for method-less routes added with
+    /// [`Route::any`], so that a request is matched in O(request segments)
+    /// rather than by scanning every route.
+    ///
+    /// Panics if any two routes collide; use [`RouterBuilder::try_build`] to
+    /// handle that case without panicking.
     ///
     /// ```
     /// use snx::http::routing::router::Router;
     ///
     /// let router = Router::builder().build();
     /// ```
-    pub fn build(mut self) -> Router {
-        self.sort_routes();
+    pub fn build(self) -> Router {
+        self.try_build().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Builds the [Router], reporting every colliding pair of routes instead
+    /// of resolving them by an arbitrary precedence (as Rocket's collider
+    /// check does at launch).
+    ///
+    /// ```
+    /// use snx::http::routing::{route::Route, router::Router};
+    ///
+    /// let result = Router::builder()
+    ///     .add_routes(&[Route::get("/posts/{id}"), Route::get("/posts/{slug}")])
+    ///     .try_build();
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    pub fn try_build(self) -> Result<Router, RouterError> {
+        let mut collisions = vec![];
 
-        Router {
-            routes: self.routes,
+        for (i, a) in self.routes.iter().enumerate() {
+            for b in &self.routes[i + 1..] {
+                if routes_collide(a, b) {
+                    collisions.push((a.clone(), b.clone()));
+                }
+            }
         }
+
+        if !collisions.is_empty() {
+            return Err(RouterError { collisions });
+        }
+
+        let routes = self.routes.clone();
+        let mut trees: HashMap<Method, Node> = HashMap::new();
+        let mut any_tree = Node::default();
+
+        for route in self.routes {
+            let segments: Vec<_> = route.path.split('/').filter(|s| !s.is_empty()).collect();
+
+            match route.method.clone() {
+                Some(method) => trees.entry(method).or_default().insert(&segments, route),
+                None => any_tree.insert(&segments, route),
+            }
+        }
+
+        let catchers = self.catchers;
+
+        Ok(Router {
+            routes,
+            trees,
+            any_tree,
+            catchers,
+        })
     }
 
-    /// Sorts the routes so that static ones come before dynamic ones.
-    fn sort_routes(&mut self) {
-        self.routes.sort_by(|a, b| {
-            (!b.path.contains(DYNAMIC_CHARS)).cmp(&!a.path.contains(DYNAMIC_CHARS))
-        });
+    /// Mounts `router`'s routes and catchers under `prefix`, as if each had
+    /// been declared with `prefix` prepended to its base path. Leading/
+    /// trailing slashes on `prefix` are normalized away.
+    ///
+    /// ```
+    /// use snx::http::routing::{route::Route, router::Router};
+    ///
+    /// let posts = Router::builder().add_route(Route::get("/{id}")).build();
+    /// let router = Router::builder().nest("/api/posts", posts).build();
+    /// ```
+    pub fn nest(mut self, prefix: &'static str, router: Router) -> Self {
+        let prefix = prefix.trim_matches('/');
+
+        let prefixed = |path: &str| -> &'static str {
+            let path = match (prefix.is_empty(), path) {
+                (true, path) => path.to_string(),
+                (false, "/") => format!("/{prefix}"),
+                (false, path) => format!("/{prefix}{path}"),
+            };
+
+            Box::leak(path.into_boxed_str())
+        };
+
+        for route in router.routes {
+            self.routes.push(Route {
+                path: prefixed(route.path),
+                ..route
+            });
+        }
+
+        for catcher in router.catchers {
+            self.catchers.push(Catcher {
+                base: prefixed(catcher.base),
+                ..catcher
+            });
+        }
+
+        self
+    }
+
+    /// Merges `router`'s routes and catchers into this builder without
+    /// mounting them under a prefix, as axum's `Router::merge` does.
+    ///
+    /// ```
+    /// use snx::http::routing::{route::Route, router::Router};
+    ///
+    /// let admin = Router::builder().add_route(Route::get("/health")).build();
+    /// let router = Router::builder().merge(admin).build();
+    /// ```
+    pub fn merge(mut self, router: Router) -> Self {
+        self.routes.extend(router.routes);
+        self.catchers.extend(router.catchers);
+
+        self
     }
 }
 
@@ -93,13 +528,12 @@ pub struct MatchedRoute {
 #[derive(Debug)]
 pub struct Router {
     routes: Vec<Route>,
+    trees: HashMap<Method, Node>,
+    any_tree: Node,
+    catchers: Vec<Catcher>,
 }
 
 impl Router {
-    pub fn new(routes: Vec<Route>) -> Self {
-        Self { routes }
-    }
-
     /// Constructs a new [RouterBuilder].
     ///
     /// ```
@@ -113,64 +547,76 @@ impl Router {
 
     /// Routes the given [Request] to the correct [Route] and returns it with possible parameters.
     ///
+    /// The request's [Method] is tried against the matching per-method tree
+    /// first, falling back to routes added with [`Route::any`] that match
+    /// any method.
+    ///
     /// ```
     /// use std::collections::HashMap;
     ///
-    /// use snx::http::{request::Request, routing::{route::Route, router::{Router, RouterBuilder}}};
+    /// use snx::http::{method::Method, request::Request, routing::{route::Route, router::{Router, RouterBuilder}}};
     ///
     /// let request = Request {
+    ///     method: Method::GET,
     ///     path: "/posts/3".to_string(),
     ///     headers: HashMap::new(),
     /// };
     ///
-    /// let route = Route::get("/posts/:id");
+    /// let route = Route::get("/posts/{id}");
     /// let router = Router::builder().add_route(route.clone()).build();
     /// let matched_route = router.route(request);
     ///
     /// assert!(matched_route.is_some());
     /// assert_eq!(matched_route.unwrap().route, route);
     /// ```
-    pub fn route(self, request: Request) -> Option<MatchedRoute> {
-        let mut matched_route = None;
-        let mut params = HashMap::new();
-
-        'outer: for route in self.routes {
-            let route_segments: Vec<_> = route.path.split('/').filter(|s| !s.is_empty()).collect();
-            let request_segments: Vec<_> =
-                request.path.split('/').filter(|s| !s.is_empty()).collect();
-
-            for (route_seg, request_seg) in route_segments.iter().zip(request_segments.iter()) {
-                if *route_seg == "*" {
-                    todo!("handle wildcard in router path");
-                } else if route_seg.starts_with(':') {
-                    let name = route_seg.strip_prefix(':').unwrap();
-                    params.insert(name.to_string(), (*request_seg).to_string());
-                } else if route_seg != request_seg {
-                    continue 'outer;
-                }
-            }
+    pub fn route(&self, request: Request) -> Option<MatchedRoute> {
+        let segments: Vec<_> = request.path.split('/').filter(|s| !s.is_empty()).collect();
 
-            if route_segments.len() > request_segments.len() {
-                if route_segments[request_segments.len()] == "*" {
-                    todo!("handle wildcard at end of route");
-                }
-
-                continue;
-            }
-
-            if request_segments.len() > route_segments.len() {
-                continue;
-            }
+        let matched = self
+            .trees
+            .get(&request.method)
+            .and_then(|tree| tree.find(&segments))
+            .or_else(|| self.any_tree.find(&segments));
 
-            matched_route = Some(MatchedRoute {
-                route,
-                params: Some(params),
-            });
+        matched.map(|(route, params)| MatchedRoute {
+            route,
+            params: Some(params),
+        })
+    }
 
-            break;
-        }
+    /// Selects the best-matching catcher for an unmatched request, scored by
+    /// (1) the longest matching base-path prefix, then (2) an exact status
+    /// match over a wildcard one, as Rocket's scoped catchers are resolved.
+    ///
+    /// ```
+    /// use snx::http::routing::router::Router;
+    ///
+    /// let router = Router::builder()
+    ///     .add_catcher(404, "/api")
+    ///     .add_catcher_any("/")
+    ///     .build();
+    ///
+    /// let catcher = router.catch(404, "/api/posts/3");
+    /// assert_eq!(catcher.unwrap().base, "/api");
+    ///
+    /// let catcher = router.catch(500, "/posts");
+    /// assert_eq!(catcher.unwrap().base, "/");
+    /// ```
+    pub fn catch(&self, status: u16, path: &str) -> Option<&Catcher> {
+        let path_segments: Vec<_> = path.split('/').filter(|s| !s.is_empty()).collect();
 
-        matched_route
+        self.catchers
+            .iter()
+            .filter(|catcher| {
+                base_matches(&path_segments, &base_segments(catcher.base))
+                    && catcher.status.is_none_or(|s| s == status)
+            })
+            .max_by_key(|catcher| {
+                (
+                    base_segments(catcher.base).len(),
+                    catcher.status.is_some(),
+                )
+            })
     }
 }
 
@@ -181,14 +627,19 @@ mod tests {
     use super::*;
 
     #[test]
-    fn it_correctly_sorts_static_routes_before_dynamic_routes() {
+    fn it_prefers_a_static_route_over_a_dynamic_one() {
         let router = Router::builder()
-            .add_routes(&[Route::get("/posts/:id"), Route::get("/posts/1")])
+            .add_routes(&[Route::get("/posts/{id}"), Route::get("/posts/1")])
             .build();
 
-        let expected = vec![Route::get("/posts/1"), Route::get("/posts/:id")];
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/posts/1".to_string(),
+            headers: HashMap::new(),
+        });
 
-        assert_eq!(router.routes, expected);
+        assert!(matched_route.is_some());
+        assert_eq!(matched_route.unwrap().route.path, "/posts/1");
     }
 
     #[test]
@@ -197,18 +648,444 @@ mod tests {
             .add_routes(&[
                 Route::get("/"),
                 Route::get("/posts"),
-                Route::get("/posts/:id"),
+                Route::get("/posts/{id}"),
                 Route::get("/posts/not-found"),
-                Route::get("/posts/:id/comments"),
+                Route::get("/posts/{id}/comments"),
             ])
             .build();
 
         let matched_route = router.route(Request {
+            method: Method::GET,
             path: "/posts/3".to_string(),
             headers: HashMap::new(),
         });
 
         assert!(matched_route.is_some());
-        assert_eq!(matched_route.unwrap().route.path, "/posts/:id".to_string());
+        assert_eq!(matched_route.unwrap().route.path, "/posts/{id}".to_string());
+    }
+
+    #[test]
+    fn it_correctly_matches_a_request_to_a_dynamic_route() {
+        let router = Router::builder()
+            .add_routes(&[
+                Route::get("/posts"),
+                Route::get("/posts/not-found"),
+                Route::get("/posts/{id}/comments"),
+            ])
+            .build();
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/posts/3/comments".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_some());
+
+        let matched_route = matched_route.unwrap();
+        assert_eq!(matched_route.route.path, "/posts/{id}/comments");
+        assert_eq!(
+            matched_route.params.unwrap().get("id"),
+            Some(&"3".to_string())
+        );
+    }
+
+    #[test]
+    fn it_matches_a_typed_int_param_and_rejects_a_non_numeric_segment() {
+        let router = Router::builder()
+            .add_route(Route::get("/posts/{id:int}"))
+            .build();
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/posts/3".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_some());
+        assert_eq!(
+            matched_route.unwrap().params.unwrap().get("id"),
+            Some(&"3".to_string())
+        );
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/posts/abc".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_none());
+    }
+
+    #[test]
+    fn it_matches_a_typed_uuid_param() {
+        let router = Router::builder()
+            .add_route(Route::get("/posts/{id:uuid}"))
+            .build();
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/posts/550e8400-e29b-41d4-a716-446655440000".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_some());
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/posts/not-a-uuid".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_none());
+    }
+
+    #[test]
+    fn it_falls_through_to_an_untyped_param_route_when_a_typed_one_does_not_match() {
+        let router = Router::builder()
+            .add_routes(&[Route::get("/posts/{id:int}"), Route::get("/posts/{slug}")])
+            .build();
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/posts/3".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_some());
+        assert_eq!(matched_route.unwrap().route.path, "/posts/{id:int}");
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/posts/abc".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_some());
+
+        let matched_route = matched_route.unwrap();
+        assert_eq!(matched_route.route.path, "/posts/{slug}");
+        assert_eq!(
+            matched_route.params.unwrap().get("slug"),
+            Some(&"abc".to_string())
+        );
+    }
+
+    #[test]
+    fn it_does_not_report_differently_typed_params_at_the_same_position_as_colliding() {
+        let result = Router::builder()
+            .add_routes(&[Route::get("/posts/{id:int}"), Route::get("/posts/{slug}")])
+            .try_build();
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn it_matches_a_catch_all_route_and_binds_the_joined_remainder() {
+        let router = Router::builder()
+            .add_route(Route::get("/files/{*path}"))
+            .build();
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/files/css/app.css".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_some());
+
+        let matched_route = matched_route.unwrap();
+        assert_eq!(matched_route.route.path, "/files/{*path}");
+        assert_eq!(
+            matched_route.params.unwrap().get("path"),
+            Some(&"css/app.css".to_string())
+        );
+    }
+
+    #[test]
+    fn it_matches_a_catch_all_route_with_a_trailing_slash() {
+        let router = Router::builder()
+            .add_route(Route::get("/files/{*path}"))
+            .build();
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/files/app.css/".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_some());
+        assert_eq!(
+            matched_route.unwrap().params.unwrap().get("path"),
+            Some(&"app.css".to_string())
+        );
+    }
+
+    #[test]
+    fn it_requires_at_least_one_segment_for_a_catch_all_route() {
+        let router = Router::builder()
+            .add_route(Route::get("/files/{*path}"))
+            .build();
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/files".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "must be the last segment")]
+    fn it_rejects_a_mid_path_catch_all_at_build_time() {
+        Router::builder().add_route(Route::get("/files/{*path}/edit")).build();
+    }
+
+    #[test]
+    fn it_dispatches_by_method_so_get_and_post_on_the_same_path_do_not_collide() {
+        let router = Router::builder()
+            .add_routes(&[Route::get("/posts"), Route::post("/posts")])
+            .build();
+
+        let matched_route = router.route(Request {
+            method: Method::POST,
+            path: "/posts".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_some());
+        assert_eq!(matched_route.unwrap().route.method, Some(Method::POST));
+    }
+
+    #[test]
+    fn it_falls_back_to_a_method_less_route_when_no_concrete_method_matches() {
+        let router = Router::builder().add_route(Route::any("/posts")).build();
+
+        let matched_route = router.route(Request {
+            method: Method::DELETE,
+            path: "/posts".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_some());
+        assert_eq!(matched_route.unwrap().route.method, None);
+    }
+
+    #[test]
+    fn it_prefers_a_concrete_method_match_over_a_method_less_route() {
+        let router = Router::builder()
+            .add_routes(&[Route::any("/posts"), Route::get("/posts")])
+            .build();
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/posts".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_some());
+        assert_eq!(matched_route.unwrap().route.method, Some(Method::GET));
+    }
+
+    #[test]
+    fn it_reports_colliding_dynamic_routes_with_different_param_names() {
+        let result = Router::builder()
+            .add_routes(&[Route::get("/posts/{id}"), Route::get("/posts/{slug}")])
+            .try_build();
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().collisions.len(), 1);
+    }
+
+    #[test]
+    fn it_reports_duplicate_static_routes() {
+        let result = Router::builder()
+            .add_routes(&[Route::get("/posts"), Route::get("/posts")])
+            .try_build();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_does_not_report_a_static_route_shadowing_a_dynamic_one_as_a_collision() {
+        let result = Router::builder()
+            .add_routes(&[Route::get("/posts/{id}"), Route::get("/posts/1")])
+            .try_build();
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn it_does_not_report_a_dynamic_route_and_a_catch_all_at_the_same_depth_as_colliding() {
+        let result = Router::builder()
+            .add_routes(&[Route::get("/assets/{id}"), Route::get("/assets/{*path}")])
+            .try_build();
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn it_does_not_report_routes_on_different_methods_as_colliding() {
+        let result = Router::builder()
+            .add_routes(&[Route::get("/posts"), Route::post("/posts")])
+            .try_build();
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "colliding route")]
+    fn it_panics_on_build_when_routes_collide() {
+        Router::builder()
+            .add_routes(&[Route::get("/posts/{id}"), Route::get("/posts/{slug}")])
+            .build();
+    }
+
+    #[test]
+    fn it_matches_a_nested_route_under_its_mount_prefix() {
+        let posts = Router::builder()
+            .add_route(Route::get("/{id}"))
+            .build();
+
+        let router = Router::builder().nest("/api/posts", posts).build();
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/api/posts/3".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_some());
+
+        let matched_route = matched_route.unwrap();
+        assert_eq!(matched_route.route.path, "/api/posts/{id}");
+        assert_eq!(
+            matched_route.params.unwrap().get("id"),
+            Some(&"3".to_string())
+        );
+    }
+
+    #[test]
+    fn it_normalizes_slashes_when_nesting() {
+        let posts = Router::builder().add_route(Route::get("/")).build();
+        let router = Router::builder().nest("/api/posts/", posts).build();
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/api/posts".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_some());
+        assert_eq!(matched_route.unwrap().route.path, "/api/posts");
+    }
+
+    #[test]
+    fn it_matches_a_nested_catch_all_route_under_its_mount_prefix() {
+        let assets = Router::builder()
+            .add_route(Route::get("/{*path}"))
+            .build();
+
+        let router = Router::builder().nest("/static", assets).build();
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/static/css/app.css".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_some());
+
+        let matched_route = matched_route.unwrap();
+        assert_eq!(matched_route.route.path, "/static/{*path}");
+        assert_eq!(
+            matched_route.params.unwrap().get("path"),
+            Some(&"css/app.css".to_string())
+        );
+    }
+
+    #[test]
+    fn it_merges_routes_without_a_prefix() {
+        let admin = Router::builder().add_route(Route::get("/health")).build();
+        let router = Router::builder().merge(admin).build();
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/health".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_some());
+    }
+
+    #[test]
+    fn it_selects_the_catcher_with_the_longest_matching_base() {
+        let router = Router::builder()
+            .add_catcher(404, "/")
+            .add_catcher(404, "/api")
+            .build();
+
+        let catcher = router.catch(404, "/api/posts/3");
+
+        assert!(catcher.is_some());
+        assert_eq!(catcher.unwrap().base, "/api");
+    }
+
+    #[test]
+    fn it_prefers_an_exact_status_catcher_over_a_wildcard_one() {
+        let router = Router::builder()
+            .add_catcher_any("/api")
+            .add_catcher(500, "/api")
+            .build();
+
+        let catcher = router.catch(500, "/api/posts");
+
+        assert!(catcher.is_some());
+        assert_eq!(catcher.unwrap().status, Some(500));
+    }
+
+    #[test]
+    fn it_does_not_match_a_catcher_registered_for_a_different_status() {
+        let router = Router::builder().add_catcher(404, "/api").build();
+
+        assert!(router.catch(500, "/api/posts").is_none());
+    }
+
+    #[test]
+    fn it_does_not_match_a_catcher_outside_its_base() {
+        let router = Router::builder().add_catcher(404, "/api").build();
+
+        assert!(router.catch(404, "/web").is_none());
+    }
+
+    #[test]
+    fn it_carries_catchers_and_routes_through_nest() {
+        let api = Router::builder()
+            .add_route(Route::get("/posts"))
+            .add_catcher(404, "/")
+            .build();
+
+        let router = Router::builder().nest("/api", api).build();
+
+        let catcher = router.catch(404, "/api/unknown");
+
+        assert!(catcher.is_some());
+        assert_eq!(catcher.unwrap().base, "/api");
+    }
+
+    #[test]
+    fn it_does_not_match_an_unknown_path() {
+        let router = Router::builder()
+            .add_routes(&[Route::get("/"), Route::get("/posts")])
+            .build();
+
+        let matched_route = router.route(Request {
+            method: Method::GET,
+            path: "/nope".to_string(),
+            headers: HashMap::new(),
+        });
+
+        assert!(matched_route.is_none());
     }
 }